@@ -0,0 +1,44 @@
+// Copyright 2020 Parity Technologies (UK) Ltd.
+// This file is part of Cumulus.
+
+// Substrate is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Substrate is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Cumulus.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Version 0 of the Cross-Consensus Message format data structures.
+
+use sp_runtime::RuntimeDebug;
+use codec::{Encode, Decode};
+
+mod multi_location;
+pub use multi_location::{
+	MultiLocation, Junctions, MAX_MULTILOCATION_LENGTH,
+	MultiLocationIterator, MultiLocationRefIterator,
+};
+
+/// A single item in a path to describe the relative location of a consensus system.
+///
+/// Each item assumes a pre-existing location as its context and is defined in terms of it.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Encode, Decode, RuntimeDebug)]
+pub enum Junction {
+	/// An indication that the context, in the absence of any other information, should refer
+	/// to the parent consensus system.
+	Parent,
+	/// A parachain of the current relay-chain.
+	Parachain { id: u32 },
+	/// A 32-byte identifier for an account of a specific network.
+	AccountId32 { id: [u8; 32] },
+	/// An index of a pallet within a Substrate-based runtime.
+	PalletInstance { id: u8 },
+	/// A non-descript index within the context location.
+	GeneralIndex { id: u128 },
+}
@@ -16,269 +16,424 @@
 
 //! Cross-Consensus Message format data structures.
 
-use sp_std::{result, convert::TryFrom};
+use sp_std::result;
 use sp_runtime::RuntimeDebug;
-use codec::{self, Encode, Decode};
+use codec::{Encode, Decode, Input, Output, Error as CodecError};
 use super::Junction;
-use crate::VersionedMultiLocation;
-
-#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Encode, Decode, RuntimeDebug)]
-pub enum MultiLocation {
-	Null,
-	X1(Junction),
-	X2(Junction, Junction),
-	X3(Junction, Junction, Junction),
-	X4(Junction, Junction, Junction, Junction),
+
+/// The maximum number of `Junction`s that a `MultiLocation` may contain.
+///
+/// This used to be a hard limit of the `X1`..`X4` enum variants; it is now a bound on the
+/// `Junctions` container so that deeper topologies (e.g. `Parachain -> PalletInstance ->
+/// AccountId32 -> GeneralIndex`) can be represented without adding further variants.
+pub const MAX_MULTILOCATION_LENGTH: usize = 8;
+
+/// A bounded, ordered sequence of `Junction`s, used as the storage for a `MultiLocation`.
+///
+/// This is a fixed-capacity analogue of a `Vec<Junction>`: pushing past
+/// `MAX_MULTILOCATION_LENGTH` fails rather than growing, so a `MultiLocation` can never exceed
+/// the bound regardless of how it is constructed.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, RuntimeDebug)]
+pub struct Junctions {
+	len: u8,
+	items: [Option<Junction>; MAX_MULTILOCATION_LENGTH],
+}
+
+impl Default for Junctions {
+	fn default() -> Self {
+		Junctions { len: 0, items: Default::default() }
+	}
+}
+
+impl Junctions {
+	/// Create a new, empty `Junctions`.
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// The number of junctions held.
+	pub fn len(&self) -> usize {
+		self.len as usize
+	}
+
+	/// Reference to the junction at index `i`, if any.
+	pub fn get(&self, i: usize) -> Option<&Junction> {
+		if i < self.len() {
+			self.items[i].as_ref()
+		} else {
+			None
+		}
+	}
+
+	/// Mutable reference to the junction at index `i`, if any.
+	pub fn get_mut(&mut self, i: usize) -> Option<&mut Junction> {
+		if i < self.len() {
+			self.items[i].as_mut()
+		} else {
+			None
+		}
+	}
+
+	/// Append `new` to the end. Returns `new` back as an error if already at capacity.
+	pub fn push(&mut self, new: Junction) -> result::Result<(), Junction> {
+		let len = self.len();
+		if len >= MAX_MULTILOCATION_LENGTH {
+			return Err(new);
+		}
+		self.items[len] = Some(new);
+		self.len += 1;
+		Ok(())
+	}
+
+	/// Prepend `new` to the front, shifting all other junctions up by one. Returns `new` back
+	/// as an error if already at capacity.
+	pub fn push_front(&mut self, new: Junction) -> result::Result<(), Junction> {
+		let len = self.len();
+		if len >= MAX_MULTILOCATION_LENGTH {
+			return Err(new);
+		}
+		for i in (0..len).rev() {
+			self.items[i + 1] = self.items[i].take();
+		}
+		self.items[0] = Some(new);
+		self.len += 1;
+		Ok(())
+	}
+
+	/// Remove and return the last junction, if any.
+	pub fn pop(&mut self) -> Option<Junction> {
+		let len = self.len();
+		if len == 0 {
+			return None;
+		}
+		let result = self.items[len - 1].take();
+		self.len -= 1;
+		result
+	}
+
+	/// Remove and return the first junction, if any, shifting the rest down by one.
+	pub fn pop_front(&mut self) -> Option<Junction> {
+		let len = self.len();
+		if len == 0 {
+			return None;
+		}
+		let result = self.items[0].take();
+		for i in 1..len {
+			self.items[i - 1] = self.items[i].take();
+		}
+		self.len -= 1;
+		result
+	}
+
+	/// Iterate over references to the contained junctions, in order.
+	pub fn iter(&self) -> impl Iterator<Item = &Junction> {
+		self.items[..self.len()].iter().filter_map(|j| j.as_ref())
+	}
 }
 
+impl Encode for Junctions {
+	fn encode_to<T: Output + ?Sized>(&self, dest: &mut T) {
+		dest.push_byte(self.len);
+		for j in self.iter() {
+			j.encode_to(dest);
+		}
+	}
+}
+
+impl Decode for Junctions {
+	fn decode<I: Input>(input: &mut I) -> result::Result<Self, CodecError> {
+		let len = input.read_byte()?;
+		if len as usize > MAX_MULTILOCATION_LENGTH {
+			return Err("MultiLocation exceeds MAX_MULTILOCATION_LENGTH".into());
+		}
+		let mut junctions = Junctions::new();
+		for _ in 0..len {
+			let j = Junction::decode(input)?;
+			// `len` was already checked against the bound above, so this cannot fail.
+			junctions.push(j).map_err(|_| "MultiLocation exceeds MAX_MULTILOCATION_LENGTH")?;
+		}
+		Ok(junctions)
+	}
+}
+
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Encode, Decode, Default, RuntimeDebug)]
+pub struct MultiLocation(Junctions);
+
 impl From<Junction> for MultiLocation {
 	fn from(x: Junction) -> Self {
-		MultiLocation::X1(x)
+		[x].into()
 	}
 }
 
 impl From<()> for MultiLocation {
 	fn from(_: ()) -> Self {
-		MultiLocation::Null
+		MultiLocation::default()
 	}
 }
 impl From<(Junction,)> for MultiLocation {
 	fn from(x: (Junction,)) -> Self {
-		MultiLocation::X1(x.0)
+		[x.0].into()
 	}
 }
 impl From<(Junction, Junction)> for MultiLocation {
 	fn from(x: (Junction, Junction)) -> Self {
-		MultiLocation::X2(x.0, x.1)
+		[x.0, x.1].into()
 	}
 }
 impl From<(Junction, Junction, Junction)> for MultiLocation {
 	fn from(x: (Junction, Junction, Junction)) -> Self {
-		MultiLocation::X3(x.0, x.1, x.2)
+		[x.0, x.1, x.2].into()
 	}
 }
 impl From<(Junction, Junction, Junction, Junction)> for MultiLocation {
 	fn from(x: (Junction, Junction, Junction, Junction)) -> Self {
-		MultiLocation::X4(x.0, x.1, x.2, x.3)
+		[x.0, x.1, x.2, x.3].into()
 	}
 }
 
 impl From<[Junction; 0]> for MultiLocation {
 	fn from(_: [Junction; 0]) -> Self {
-		MultiLocation::Null
+		MultiLocation::default()
 	}
 }
 impl From<[Junction; 1]> for MultiLocation {
 	fn from(x: [Junction; 1]) -> Self {
 		let [x0] = x;
-		MultiLocation::X1(x0)
+		let mut junctions = Junctions::new();
+		junctions.push(x0).expect("array of length 1 is within MAX_MULTILOCATION_LENGTH; qed");
+		MultiLocation(junctions)
 	}
 }
 impl From<[Junction; 2]> for MultiLocation {
 	fn from(x: [Junction; 2]) -> Self {
 		let [x0, x1] = x;
-		MultiLocation::X2(x0, x1)
+		let mut junctions = Junctions::new();
+		junctions.push(x0).expect("array of length 2 is within MAX_MULTILOCATION_LENGTH; qed");
+		junctions.push(x1).expect("array of length 2 is within MAX_MULTILOCATION_LENGTH; qed");
+		MultiLocation(junctions)
 	}
 }
 impl From<[Junction; 3]> for MultiLocation {
 	fn from(x: [Junction; 3]) -> Self {
 		let [x0, x1, x2] = x;
-		MultiLocation::X3(x0, x1, x2)
+		let mut junctions = Junctions::new();
+		for j in [x0, x1, x2] {
+			junctions.push(j).expect("array of length 3 is within MAX_MULTILOCATION_LENGTH; qed");
+		}
+		MultiLocation(junctions)
 	}
 }
 impl From<[Junction; 4]> for MultiLocation {
 	fn from(x: [Junction; 4]) -> Self {
 		let [x0, x1, x2, x3] = x;
-		MultiLocation::X4(x0, x1, x2, x3)
+		let mut junctions = Junctions::new();
+		for j in [x0, x1, x2, x3] {
+			junctions.push(j).expect("array of length 4 is within MAX_MULTILOCATION_LENGTH; qed");
+		}
+		MultiLocation(junctions)
 	}
 }
 
+/// An owned, forward iterator over the junctions of a [`MultiLocation`], yielded by
+/// [`IntoIterator for MultiLocation`](MultiLocation).
 pub struct MultiLocationIterator(MultiLocation);
 impl Iterator for MultiLocationIterator {
 	type Item = Junction;
 	fn next(&mut self) -> Option<Junction> {
 		self.0.take_first()
 	}
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let n = self.len();
+		(n, Some(n))
+	}
 }
-
-pub struct MultiLocationReverseIterator(MultiLocation);
-impl Iterator for MultiLocationReverseIterator {
-	type Item = Junction;
-	fn next(&mut self) -> Option<Junction> {
+impl DoubleEndedIterator for MultiLocationIterator {
+	fn next_back(&mut self) -> Option<Junction> {
 		self.0.take_last()
 	}
 }
+impl ExactSizeIterator for MultiLocationIterator {
+	fn len(&self) -> usize {
+		self.0.len()
+	}
+}
 
-pub struct MultiLocationRefIterator<'a>(&'a MultiLocation, usize);
+/// A borrowing, forward iterator over the junctions of a [`MultiLocation`], yielded by
+/// [`IntoIterator for &MultiLocation`](MultiLocation).
+pub struct MultiLocationRefIterator<'a> {
+	loc: &'a MultiLocation,
+	front: usize,
+	back: usize,
+}
+impl<'a> MultiLocationRefIterator<'a> {
+	fn new(loc: &'a MultiLocation) -> Self {
+		MultiLocationRefIterator { loc, front: 0, back: loc.len() }
+	}
+}
 impl<'a> Iterator for MultiLocationRefIterator<'a> {
 	type Item = &'a Junction;
 	fn next(&mut self) -> Option<&'a Junction> {
-		let result = self.0.at(self.1);
-		self.1 += 1;
+		if self.front >= self.back {
+			return None;
+		}
+		let result = self.loc.at(self.front);
+		self.front += 1;
 		result
 	}
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let n = self.len();
+		(n, Some(n))
+	}
+}
+impl<'a> DoubleEndedIterator for MultiLocationRefIterator<'a> {
+	fn next_back(&mut self) -> Option<&'a Junction> {
+		if self.front >= self.back {
+			return None;
+		}
+		self.back -= 1;
+		self.loc.at(self.back)
+	}
+}
+impl<'a> ExactSizeIterator for MultiLocationRefIterator<'a> {
+	fn len(&self) -> usize {
+		self.back - self.front
+	}
+}
+
+impl IntoIterator for MultiLocation {
+	type Item = Junction;
+	type IntoIter = MultiLocationIterator;
+	fn into_iter(self) -> MultiLocationIterator {
+		MultiLocationIterator(self)
+	}
 }
 
-pub struct MultiLocationReverseRefIterator<'a>(&'a MultiLocation, usize);
-impl<'a> Iterator for MultiLocationReverseRefIterator<'a> {
+impl<'a> IntoIterator for &'a MultiLocation {
 	type Item = &'a Junction;
-	fn next(&mut self) -> Option<&'a Junction> {
-		self.1 += 1;
-		self.0.at(self.0.len().checked_sub(self.1)?)
+	type IntoIter = MultiLocationRefIterator<'a>;
+	fn into_iter(self) -> MultiLocationRefIterator<'a> {
+		MultiLocationRefIterator::new(self)
+	}
+}
+
+impl core::ops::Index<usize> for MultiLocation {
+	type Output = Junction;
+	fn index(&self, i: usize) -> &Junction {
+		self.at(i).expect("MultiLocation index out of bounds")
+	}
+}
+
+// Note: the real `core::iter::FromIterator::from_iter` signature returns `Self`, not a
+// `Result`, so it cannot report failure as a value — `.collect::<MultiLocation>()` therefore
+// panics past the bound, unlike `try_from_iter` below, which returns `Err`.
+impl core::iter::FromIterator<Junction> for MultiLocation {
+	/// Build a `MultiLocation` by pushing every junction from `iter` in order.
+	///
+	/// # Panics
+	///
+	/// Panics if `iter` yields more than [`MAX_MULTILOCATION_LENGTH`] junctions. `FromIterator`
+	/// has no way to report failure, so for a fallible equivalent that hands back everything
+	/// that did fit, use [`MultiLocation::try_from_iter`].
+	fn from_iter<T: IntoIterator<Item = Junction>>(iter: T) -> Self {
+		MultiLocation::try_from_iter(iter)
+			.expect("MultiLocation::from_iter: iterator exceeded MAX_MULTILOCATION_LENGTH junctions")
 	}
 }
 
 impl MultiLocation {
 	pub fn first(&self) -> Option<&Junction> {
-		match &self {
-			MultiLocation::Null => None,
-			MultiLocation::X1(ref a) => Some(a),
-			MultiLocation::X2(ref a, ..) => Some(a),
-			MultiLocation::X3(ref a, ..) => Some(a),
-			MultiLocation::X4(ref a, ..) => Some(a),
-		}
+		self.0.get(0)
 	}
 	pub fn last(&self) -> Option<&Junction> {
-		match &self {
-			MultiLocation::Null => None,
-			MultiLocation::X1(ref a) => Some(a),
-			MultiLocation::X2(.., ref a) => Some(a),
-			MultiLocation::X3(.., ref a) => Some(a),
-			MultiLocation::X4(.., ref a) => Some(a),
-		}
+		self.0.len().checked_sub(1).and_then(|i| self.0.get(i))
 	}
-	pub fn split_first(self) -> (MultiLocation, Option<Junction>) {
-		match self {
-			MultiLocation::Null => (MultiLocation::Null, None),
-			MultiLocation::X1(a) => (MultiLocation::Null, Some(a)),
-			MultiLocation::X2(a, b) => (MultiLocation::X1(b), Some(a)),
-			MultiLocation::X3(a, b, c) => (MultiLocation::X2(b, c), Some(a)),
-			MultiLocation::X4(a, b, c ,d) => (MultiLocation::X3(b, c, d), Some(a)),
-		}
+	pub fn split_first(mut self) -> (MultiLocation, Option<Junction>) {
+		let first = self.0.pop_front();
+		(self, first)
 	}
-	pub fn split_last(self) -> (MultiLocation, Option<Junction>) {
-		match self {
-			MultiLocation::Null => (MultiLocation::Null, None),
-			MultiLocation::X1(a) => (MultiLocation::Null, Some(a)),
-			MultiLocation::X2(a, b) => (MultiLocation::X1(a), Some(b)),
-			MultiLocation::X3(a, b, c) => (MultiLocation::X2(a, b), Some(c)),
-			MultiLocation::X4(a, b, c ,d) => (MultiLocation::X3(a, b, c), Some(d)),
-		}
+	pub fn split_last(mut self) -> (MultiLocation, Option<Junction>) {
+		let last = self.0.pop();
+		(self, last)
 	}
 	pub fn take_first(&mut self) -> Option<Junction> {
-		let mut d = MultiLocation::Null;
-		sp_std::mem::swap(&mut *self, &mut d);
-		let (tail, head) = d.split_first();
-		*self = tail;
-		head
+		self.0.pop_front()
 	}
 	pub fn take_last(&mut self) -> Option<Junction> {
-		let mut d = MultiLocation::Null;
-		sp_std::mem::swap(&mut *self, &mut d);
-		let (head, tail) = d.split_last();
-		*self = head;
-		tail
-	}
-	pub fn pushed_with(self, new: Junction) -> result::Result<Self, Self> {
-		Ok(match self {
-			MultiLocation::Null => MultiLocation::X1(new),
-			MultiLocation::X1(a) => MultiLocation::X2(a, new),
-			MultiLocation::X2(a, b) => MultiLocation::X3(a, b, new),
-			MultiLocation::X3(a, b, c) => MultiLocation::X4(a, b, c, new),
-			s => Err(s)?,
-		})
-	}
-	pub fn pushed_front_with(self, new: Junction) -> result::Result<Self, Self> {
-		Ok(match self {
-			MultiLocation::Null => MultiLocation::X1(new),
-			MultiLocation::X1(a) => MultiLocation::X2(new, a),
-			MultiLocation::X2(a, b) => MultiLocation::X3(new, a, b),
-			MultiLocation::X3(a, b, c) => MultiLocation::X4(new, a, b, c),
-			s => Err(s)?,
-		})
+		self.0.pop()
 	}
-	pub fn len(&self) -> usize {
-		match &self {
-			MultiLocation::Null => 0,
-			MultiLocation::X1(..) => 1,
-			MultiLocation::X2(..) => 2,
-			MultiLocation::X3(..) => 3,
-			MultiLocation::X4(..) => 4,
+	pub fn pushed_with(mut self, new: Junction) -> result::Result<Self, Self> {
+		match self.0.push(new) {
+			Ok(()) => Ok(self),
+			Err(_) => Err(self),
+		}
+	}
+	pub fn pushed_front_with(mut self, new: Junction) -> result::Result<Self, Self> {
+		match self.0.push_front(new) {
+			Ok(()) => Ok(self),
+			Err(_) => Err(self),
 		}
 	}
+	pub fn len(&self) -> usize {
+		self.0.len()
+	}
 
 	pub fn at(&self, i: usize) -> Option<&Junction> {
-		Some(match (i, &self) {
-			(0, MultiLocation::X1(ref a)) => a,
-			(0, MultiLocation::X2(ref a, ..)) => a,
-			(0, MultiLocation::X3(ref a, ..)) => a,
-			(0, MultiLocation::X4(ref a, ..)) => a,
-			(1, MultiLocation::X2(_, ref a)) => a,
-			(1, MultiLocation::X3(_, ref a, ..)) => a,
-			(1, MultiLocation::X4(_, ref a, ..)) => a,
-			(2, MultiLocation::X3(_, _, ref a)) => a,
-			(2, MultiLocation::X4(_, _, ref a, ..)) => a,
-			(3, MultiLocation::X4(_, _, _, ref a)) => a,
-			_ => return None,
-		})
+		self.0.get(i)
 	}
 
 	pub fn at_mut(&mut self, i: usize) -> Option<&mut Junction> {
-		Some(match (i, self) {
-			(0, MultiLocation::X1(ref mut a)) => a,
-			(0, MultiLocation::X2(ref mut a, ..)) => a,
-			(0, MultiLocation::X3(ref mut a, ..)) => a,
-			(0, MultiLocation::X4(ref mut a, ..)) => a,
-			(1, MultiLocation::X2(_, ref mut a)) => a,
-			(1, MultiLocation::X3(_, ref mut a, ..)) => a,
-			(1, MultiLocation::X4(_, ref mut a, ..)) => a,
-			(2, MultiLocation::X3(_, _, ref mut a)) => a,
-			(2, MultiLocation::X4(_, _, ref mut a, ..)) => a,
-			(3, MultiLocation::X4(_, _, _, ref mut a)) => a,
-			_ => return None,
-		})
+		self.0.get_mut(i)
 	}
 
+	/// A thin wrapper around [`IntoIterator for &MultiLocation`](MultiLocation).
 	pub fn iter(&self) -> MultiLocationRefIterator {
-		MultiLocationRefIterator(&self, 0)
+		MultiLocationRefIterator::new(self)
 	}
-	pub fn iter_rev(&self) -> MultiLocationReverseRefIterator {
-		MultiLocationReverseRefIterator(&self, 0)
+	/// A thin wrapper around `self.iter().rev()`.
+	pub fn iter_rev(&self) -> impl DoubleEndedIterator<Item = &Junction> + '_ {
+		self.iter().rev()
 	}
+	/// A thin wrapper around [`IntoIterator for MultiLocation`](MultiLocation).
 	pub fn into_iter(self) -> MultiLocationIterator {
 		MultiLocationIterator(self)
 	}
-	pub fn into_iter_rev(self) -> MultiLocationReverseIterator {
-		MultiLocationReverseIterator(self)
+	/// A thin wrapper around `self.into_iter().rev()`.
+	pub fn into_iter_rev(self) -> impl DoubleEndedIterator<Item = Junction> {
+		MultiLocationIterator(self).rev()
 	}
 
 	pub fn push(&mut self, new: Junction) -> result::Result<(), ()> {
-		let mut n = MultiLocation::Null;
-		sp_std::mem::swap(&mut *self, &mut n);
-		match n.pushed_with(new) {
-			Ok(result) => { *self = result; Ok(()) }
-			Err(old) => { *self = old; Err(()) }
-		}
+		self.0.push(new).map_err(|_| ())
 	}
 
 	pub fn push_front(&mut self, new: Junction) -> result::Result<(), ()> {
-		let mut n = MultiLocation::Null;
-		sp_std::mem::swap(&mut *self, &mut n);
-		match n.pushed_front_with(new) {
-			Ok(result) => { *self = result; Ok(()) }
-			Err(old) => { *self = old; Err(()) }
-		}
+		self.0.push_front(new).map_err(|_| ())
 	}
 
-	/// Returns partial result as error in case of failure (e.g. because out of space).
-	pub fn appended_with(self, new: MultiLocation) -> result::Result<Self, Self> {
-		let mut result= self;
-		for j in new.into_iter() {
+	/// Build a `MultiLocation` by pushing every junction from `iter` in order, starting from
+	/// empty.
+	///
+	/// Returns `Err` holding everything that did fit if `iter` yields more junctions than
+	/// [`MAX_MULTILOCATION_LENGTH`] allows, rather than silently truncating.
+	pub fn try_from_iter(iter: impl IntoIterator<Item = Junction>) -> result::Result<Self, Self> {
+		MultiLocation::default().try_extend(iter)
+	}
+
+	/// As [`Self::try_from_iter`], but extending an existing location rather than starting
+	/// from empty.
+	fn try_extend(self, iter: impl IntoIterator<Item = Junction>) -> result::Result<Self, Self> {
+		let mut result = self;
+		for j in iter {
 			result = result.pushed_with(j)?;
 		}
 		Ok(result)
 	}
 
+	/// Returns partial result as error in case of failure (e.g. because out of space).
+	pub fn appended_with(self, new: MultiLocation) -> result::Result<Self, Self> {
+		self.try_extend(new)
+	}
+
 	/// Ensure that the `prefix` len plus the `self` len is less than the max length, if not
 	/// the result is undefined.
 	pub fn prepend_with(&mut self, prefix: &MultiLocation) {
@@ -296,19 +451,282 @@ impl MultiLocation {
 			let _ = self.push_front(j);
 		}
 	}
-}
 
-impl From<MultiLocation> for VersionedMultiLocation {
-	fn from(x: MultiLocation) -> Self {
-		VersionedMultiLocation::V0(x)
+	/// Collapse any adjacent `X, Parent` pair (with `X != Parent`) into nothing, repeatedly,
+	/// leaving only a leading run of `Parent`s (the number of levels to ascend) followed by the
+	/// junctions actually reached.
+	///
+	/// This is the same cancellation `prepend_with` already performs, exposed as a standalone
+	/// normalization step so two differently-written-but-equivalent locations compare equal
+	/// once both are canonicalized.
+	pub fn canonicalize(&mut self) {
+		let mut result = MultiLocation::default();
+		for j in sp_std::mem::take(self).into_iter() {
+			if j == Junction::Parent {
+				if let Some(x) = result.last() {
+					if x != &Junction::Parent {
+						result.take_last();
+						continue;
+					}
+				}
+			}
+			// `result` can never exceed the length of the original `self`, so this cannot fail.
+			let _ = result.push(j);
+		}
+		*self = result;
+	}
+
+	/// `self`, canonicalized. See [`Self::canonicalize`].
+	pub fn canonical(mut self) -> Self {
+		self.canonicalize();
+		self
+	}
+
+	/// The number of leading `Parent` junctions, i.e. the number of levels this location
+	/// ascends before descending again.
+	pub fn num_ascents(&self) -> usize {
+		self.iter().take_while(|j| **j == Junction::Parent).count()
+	}
+
+	/// Whether this location ascends at all, i.e. is relative to some ancestor rather than
+	/// wholly interior to the current context.
+	pub fn is_relative(&self) -> bool {
+		self.num_ascents() > 0
+	}
+
+	/// Whether this location contains no `Parent` junctions at all.
+	pub fn is_interior(&self) -> bool {
+		self.iter().all(|j| j != &Junction::Parent)
 	}
 }
 
-impl TryFrom<VersionedMultiLocation> for MultiLocation {
-	type Error = ();
-	fn try_from(x: VersionedMultiLocation) -> result::Result<Self, ()> {
-		match x {
-			VersionedMultiLocation::V0(x) => Ok(x),
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use sp_std::{vec, vec::Vec};
+
+	fn deep(n: usize) -> MultiLocation {
+		let mut m = MultiLocation::default();
+		for i in 0..n {
+			m = m.pushed_with(Junction::GeneralIndex { id: i as u128 }).unwrap();
+		}
+		m
+	}
+
+	#[test]
+	fn codec_round_trips_for_every_length() {
+		for n in 0..=MAX_MULTILOCATION_LENGTH {
+			let original = deep(n);
+			let encoded = original.encode();
+			let decoded = MultiLocation::decode(&mut &encoded[..]).unwrap();
+			assert_eq!(original, decoded);
 		}
 	}
-}
\ No newline at end of file
+
+	#[test]
+	fn v0_wire_format_is_preserved_for_small_lengths() {
+		// `Null`, `X1`..`X4` used to encode as a variant index followed by the fields, in
+		// order; the new representation must produce byte-identical output for every length
+		// the old format could express.
+		let m: MultiLocation = ().into();
+		assert_eq!(m.encode(), vec![0u8]);
+
+		let m: MultiLocation = Junction::Parent.into();
+		let mut expected = vec![1u8];
+		expected.extend(Junction::Parent.encode());
+		assert_eq!(m.encode(), expected);
+
+		for n in 2..=4usize {
+			let junctions: Vec<Junction> = (0..n as u128).map(|id| Junction::GeneralIndex { id }).collect();
+			let m = deep(n);
+			let mut expected = vec![n as u8];
+			for j in &junctions {
+				expected.extend(j.encode());
+			}
+			assert_eq!(m.encode(), expected);
+		}
+	}
+
+	#[test]
+	fn push_fails_past_the_bound() {
+		let mut m = deep(MAX_MULTILOCATION_LENGTH);
+		assert_eq!(m.len(), MAX_MULTILOCATION_LENGTH);
+		assert!(m.push(Junction::Parent).is_err());
+		assert!(m.push_front(Junction::Parent).is_err());
+	}
+
+	#[test]
+	fn pushed_with_fails_past_the_bound_and_returns_self() {
+		let m = deep(MAX_MULTILOCATION_LENGTH);
+		let m = m.pushed_with(Junction::Parent).unwrap_err();
+		assert_eq!(m.len(), MAX_MULTILOCATION_LENGTH);
+	}
+
+	#[test]
+	fn iteration_order_matches_push_order() {
+		let m = deep(4);
+		let forward: Vec<_> = m.iter().cloned().collect();
+		let expected: Vec<_> = (0..4u128).map(|id| Junction::GeneralIndex { id }).collect();
+		assert_eq!(forward, expected);
+
+		let reverse: Vec<_> = m.iter_rev().cloned().collect();
+		let mut expected_rev = expected.clone();
+		expected_rev.reverse();
+		assert_eq!(reverse, expected_rev);
+	}
+
+	#[test]
+	fn appended_with_respects_the_boundary() {
+		let a = deep(MAX_MULTILOCATION_LENGTH - 1);
+		let b = deep(2);
+		// One junction fits, the second does not: the result is an error carrying the
+		// partially-appended location.
+		let err = a.appended_with(b).unwrap_err();
+		assert_eq!(err.len(), MAX_MULTILOCATION_LENGTH);
+
+		let a = deep(MAX_MULTILOCATION_LENGTH - 2);
+		let b = deep(2);
+		let ok = a.appended_with(b).unwrap();
+		assert_eq!(ok.len(), MAX_MULTILOCATION_LENGTH);
+	}
+
+	#[test]
+	fn prepend_with_at_the_length_boundary() {
+		let mut m = deep(MAX_MULTILOCATION_LENGTH - 1);
+		let prefix: MultiLocation = Junction::Parachain { id: 42 }.into();
+		// Fits exactly within the bound.
+		m.prepend_with(&prefix);
+		assert_eq!(m.len(), MAX_MULTILOCATION_LENGTH);
+
+		let mut m = deep(MAX_MULTILOCATION_LENGTH);
+		let prefix: MultiLocation = Junction::Parachain { id: 42 }.into();
+		// No room left; `prepend_with` fails silently and leaves `self` unchanged.
+		m.prepend_with(&prefix);
+		assert_eq!(m.len(), MAX_MULTILOCATION_LENGTH);
+	}
+
+	#[test]
+	fn canonicalize_collapses_parachain_then_parent() {
+		let m: MultiLocation = [Junction::Parachain { id: 1 }, Junction::Parent].into();
+		assert_eq!(m.canonical(), MultiLocation::default());
+	}
+
+	#[test]
+	fn canonicalize_preserves_leading_ascents() {
+		let m: MultiLocation = [Junction::Parent, Junction::Parent, Junction::Parachain { id: 1 }].into();
+		let expected: MultiLocation =
+			[Junction::Parent, Junction::Parent, Junction::Parachain { id: 1 }].into();
+		assert_eq!(m.canonical(), expected);
+	}
+
+	#[test]
+	fn canonicalize_collapses_repeatedly() {
+		let m: MultiLocation = [
+			Junction::Parachain { id: 1 },
+			Junction::Parachain { id: 2 },
+			Junction::Parent,
+			Junction::Parent,
+		]
+		.into();
+		assert_eq!(m.canonical(), MultiLocation::default());
+	}
+
+	#[test]
+	fn canonicalize_is_idempotent() {
+		let m: MultiLocation = [
+			Junction::Parent,
+			Junction::Parachain { id: 1 },
+			Junction::Parachain { id: 2 },
+			Junction::Parent,
+		]
+		.into();
+		let once = m.clone().canonical();
+		let twice = once.clone().canonical();
+		assert_eq!(once, twice);
+	}
+
+	#[test]
+	fn canonicalize_interacts_with_appended_with() {
+		let base: MultiLocation = [Junction::Parachain { id: 1 }].into();
+		let suffix: MultiLocation = [Junction::Parent, Junction::Parachain { id: 2 }].into();
+		let appended = base.appended_with(suffix).unwrap();
+		assert_eq!(appended.canonical(), [Junction::Parachain { id: 2 }].into());
+	}
+
+	#[test]
+	fn num_ascents_and_is_relative_and_is_interior() {
+		let interior: MultiLocation = [Junction::Parachain { id: 1 }].into();
+		assert_eq!(interior.num_ascents(), 0);
+		assert!(!interior.is_relative());
+		assert!(interior.is_interior());
+
+		let relative: MultiLocation =
+			[Junction::Parent, Junction::Parent, Junction::Parachain { id: 1 }].into();
+		assert_eq!(relative.num_ascents(), 2);
+		assert!(relative.is_relative());
+		assert!(!relative.is_interior());
+	}
+
+	#[test]
+	fn for_loop_over_ref_visits_junctions_in_order() {
+		let m = deep(4);
+		let expected: Vec<_> = (0..4u128).map(|id| Junction::GeneralIndex { id }).collect();
+		let mut visited = Vec::new();
+		for j in &m {
+			visited.push(j.clone());
+		}
+		assert_eq!(visited, expected);
+	}
+
+	#[test]
+	fn ref_into_iter_rev_visits_junctions_in_reverse_order() {
+		let m = deep(4);
+		let mut expected: Vec<_> = (0..4u128).map(|id| Junction::GeneralIndex { id }).collect();
+		expected.reverse();
+		let visited: Vec<_> = (&m).into_iter().rev().cloned().collect();
+		assert_eq!(visited, expected);
+	}
+
+	#[test]
+	fn owned_into_iter_and_rev_agree_with_ref_iteration() {
+		let m = deep(3);
+		let forward: Vec<_> = m.clone().into_iter().collect();
+		let expected: Vec<_> = m.iter().cloned().collect();
+		assert_eq!(forward, expected);
+
+		let backward: Vec<_> = m.clone().into_iter().rev().collect();
+		let mut expected_rev = expected.clone();
+		expected_rev.reverse();
+		assert_eq!(backward, expected_rev);
+	}
+
+	#[test]
+	fn collect_builds_a_multi_location() {
+		let expected = deep(4);
+		let collected: MultiLocation = (0..4u128).map(|id| Junction::GeneralIndex { id }).collect();
+		assert_eq!(collected, expected);
+	}
+
+	#[test]
+	fn try_from_iter_fails_past_the_bound_without_truncating() {
+		let err = MultiLocation::try_from_iter(
+			(0..=MAX_MULTILOCATION_LENGTH as u128).map(|id| Junction::GeneralIndex { id }),
+		)
+		.unwrap_err();
+		assert_eq!(err.len(), MAX_MULTILOCATION_LENGTH);
+	}
+
+	#[test]
+	fn index_returns_the_junction_at_that_position() {
+		let m = deep(3);
+		assert_eq!(&m[0], &Junction::GeneralIndex { id: 0 });
+		assert_eq!(&m[2], &Junction::GeneralIndex { id: 2 });
+	}
+
+	#[test]
+	#[should_panic]
+	fn index_out_of_bounds_panics() {
+		let m = deep(1);
+		let _ = &m[1];
+	}
+}
@@ -0,0 +1,76 @@
+// Copyright 2020 Parity Technologies (UK) Ltd.
+// This file is part of Cumulus.
+
+// Substrate is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Substrate is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Cumulus.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Version 1 of the Cross-Consensus Message format data structures.
+//!
+//! This extends `v0` with `Junction::GeneralKey`, which has no representation in the `v0`
+//! format; locations using it can only be downgraded to `v0` by dropping it, which is why the
+//! downgrade is fallible.
+
+use sp_std::vec::Vec;
+use sp_runtime::RuntimeDebug;
+use codec::{Encode, Decode};
+use crate::v0;
+
+mod multi_location;
+pub use multi_location::MultiLocation;
+
+/// A single item in a path to describe the relative location of a consensus system.
+///
+/// Each item assumes a pre-existing location as its context and is defined in terms of it.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Encode, Decode, RuntimeDebug)]
+pub enum Junction {
+	/// An indication that the context, in the absence of any other information, should refer
+	/// to the parent consensus system.
+	Parent,
+	/// A parachain of the current relay-chain.
+	Parachain { id: u32 },
+	/// A 32-byte identifier for an account of a specific network.
+	AccountId32 { id: [u8; 32] },
+	/// An index of a pallet within a Substrate-based runtime.
+	PalletInstance { id: u8 },
+	/// A non-descript index within the context location.
+	GeneralIndex { id: u128 },
+	/// A named, opaque key fixed to this location. Introduced in V1; has no `v0` equivalent.
+	GeneralKey { id: Vec<u8> },
+}
+
+impl From<v0::Junction> for Junction {
+	fn from(old: v0::Junction) -> Self {
+		match old {
+			v0::Junction::Parent => Junction::Parent,
+			v0::Junction::Parachain { id } => Junction::Parachain { id },
+			v0::Junction::AccountId32 { id } => Junction::AccountId32 { id },
+			v0::Junction::PalletInstance { id } => Junction::PalletInstance { id },
+			v0::Junction::GeneralIndex { id } => Junction::GeneralIndex { id },
+		}
+	}
+}
+
+impl sp_std::convert::TryFrom<Junction> for v0::Junction {
+	type Error = ();
+	fn try_from(new: Junction) -> Result<Self, ()> {
+		Ok(match new {
+			Junction::Parent => v0::Junction::Parent,
+			Junction::Parachain { id } => v0::Junction::Parachain { id },
+			Junction::AccountId32 { id } => v0::Junction::AccountId32 { id },
+			Junction::PalletInstance { id } => v0::Junction::PalletInstance { id },
+			Junction::GeneralIndex { id } => v0::Junction::GeneralIndex { id },
+			// No `v0` junction can represent a general key.
+			Junction::GeneralKey { .. } => return Err(()),
+		})
+	}
+}
@@ -0,0 +1,86 @@
+// Copyright 2020 Parity Technologies (UK) Ltd.
+// This file is part of Cumulus.
+
+// Substrate is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Substrate is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Cumulus.  If not, see <http://www.gnu.org/licenses/>.
+
+use sp_std::{vec::Vec, result, convert::TryFrom};
+use sp_runtime::RuntimeDebug;
+use codec::{Encode, Decode, Input, Error as CodecError};
+use super::Junction;
+use crate::v0::{self, MAX_MULTILOCATION_LENGTH};
+
+/// A relative path between two locations, expressed in terms of V1 junctions.
+///
+/// Bounded by the same [`MAX_MULTILOCATION_LENGTH`](crate::v0::MAX_MULTILOCATION_LENGTH) as
+/// `v0::MultiLocation`, so that a location always fits in either format or fails to convert
+/// rather than silently truncating.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Encode, Default, RuntimeDebug)]
+pub struct MultiLocation(Vec<Junction>);
+
+impl Decode for MultiLocation {
+	fn decode<I: Input>(input: &mut I) -> result::Result<Self, CodecError> {
+		let items = Vec::<Junction>::decode(input)?;
+		if items.len() > MAX_MULTILOCATION_LENGTH {
+			return Err("MultiLocation exceeds MAX_MULTILOCATION_LENGTH".into());
+		}
+		Ok(MultiLocation(items))
+	}
+}
+
+impl MultiLocation {
+	/// The number of junctions held.
+	pub fn len(&self) -> usize {
+		self.0.len()
+	}
+
+	/// Append `new` to the end. Returns `new` back as an error if already at capacity.
+	pub fn push(&mut self, new: Junction) -> result::Result<(), Junction> {
+		if self.0.len() >= MAX_MULTILOCATION_LENGTH {
+			return Err(new);
+		}
+		self.0.push(new);
+		Ok(())
+	}
+
+	pub fn iter(&self) -> impl Iterator<Item = &Junction> {
+		self.0.iter()
+	}
+
+	pub fn into_iter(self) -> impl Iterator<Item = Junction> {
+		self.0.into_iter()
+	}
+}
+
+impl From<v0::MultiLocation> for MultiLocation {
+	fn from(old: v0::MultiLocation) -> Self {
+		let mut result = MultiLocation::default();
+		for j in old.into_iter() {
+			// `old` already satisfied the same `MAX_MULTILOCATION_LENGTH` bound.
+			result.push(j.into()).expect("v0::MultiLocation respects MAX_MULTILOCATION_LENGTH; qed");
+		}
+		result
+	}
+}
+
+impl TryFrom<MultiLocation> for v0::MultiLocation {
+	type Error = ();
+	fn try_from(new: MultiLocation) -> result::Result<Self, ()> {
+		let mut result = v0::MultiLocation::default();
+		for j in new.into_iter() {
+			let j = v0::Junction::try_from(j)?;
+			result.push(j).map_err(|_| ())?;
+		}
+		Ok(result)
+	}
+}